@@ -0,0 +1,81 @@
+use crate::vector2::Vector2;
+
+/// Clips a subject polygon against a convex clip polygon
+///
+/// Implements the Sutherland–Hodgman algorithm: the clip polygon is treated as
+/// an ordered list of half-planes (inside = left of each directed edge) and the
+/// subject is intersected against them one edge at a time. The clip polygon is
+/// assumed to be convex and counter-clockwise (e.g. an axis-aligned viewBox
+/// rectangle). The result may be empty when the polygons do not overlap.
+///
+/// # Examples
+///
+/// ```
+/// use svg_polygon_parser::clip::clip_polygon;
+/// use svg_polygon_parser::vector2::Vector2;
+///
+/// let subject = [
+///     Vector2::new(-1.0, -1.0),
+///     Vector2::new(3.0, -1.0),
+///     Vector2::new(3.0, 3.0),
+///     Vector2::new(-1.0, 3.0),
+/// ];
+/// let window = [
+///     Vector2::new(0.0, 0.0),
+///     Vector2::new(2.0, 0.0),
+///     Vector2::new(2.0, 2.0),
+///     Vector2::new(0.0, 2.0),
+/// ];
+/// let clipped = clip_polygon(&subject, &window);
+/// assert_eq!(clipped.len(), 4);
+/// ```
+pub fn clip_polygon(subject: &[Vector2], clip: &[Vector2]) -> Vec<Vector2> {
+    let cn = clip.len();
+    if cn < 3 {
+        return subject.to_vec();
+    }
+
+    let mut output: Vec<Vector2> = subject.to_vec();
+
+    for i in 0..cn {
+        if output.is_empty() {
+            break;
+        }
+        let start = &clip[i];
+        let end = &clip[(i + 1) % cn];
+        let input = output;
+        output = Vec::with_capacity(input.len());
+
+        let len = input.len();
+        for j in 0..len {
+            let s = &input[(j + len - 1) % len];
+            let e = &input[j];
+            let s_inside = is_inside(start, end, s);
+            let e_inside = is_inside(start, end, e);
+
+            if e_inside {
+                if !s_inside {
+                    output.push(intersection(start, end, s, e));
+                }
+                output.push(*e);
+            } else if s_inside {
+                output.push(intersection(start, end, s, e));
+            }
+        }
+    }
+
+    output
+}
+
+/// Returns true when `p` lies on the inside (left) of the directed clip edge.
+fn is_inside(start: &Vector2, end: &Vector2, p: &Vector2) -> bool {
+    (end - start).cross(&(p - start)) >= 0.0
+}
+
+/// Intersection of the subject segment `(s, e)` with the clip edge line.
+fn intersection(start: &Vector2, end: &Vector2, s: &Vector2, e: &Vector2) -> Vector2 {
+    let clip_dir = end - start;
+    let seg_dir = e - s;
+    let t = clip_dir.cross(&(start - s)) / clip_dir.cross(&seg_dir);
+    s + &seg_dir.multi(t)
+}
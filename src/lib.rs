@@ -0,0 +1,5 @@
+pub mod clip;
+pub mod polygon;
+pub mod polylabel;
+pub mod triangulate;
+pub mod vector2;
@@ -0,0 +1,118 @@
+use crate::vector2::Vector2;
+
+/// Returns the signed area of a polygon
+///
+/// The sign follows the shoelace convention: counter-clockwise winding
+/// yields a positive area and clockwise winding a negative one.
+///
+/// # Examples
+///
+/// ```
+/// use svg_polygon_parser::polygon::signed_area;
+/// use svg_polygon_parser::vector2::Vector2;
+///
+/// let square = [
+///     Vector2::new(0.0, 0.0),
+///     Vector2::new(2.0, 0.0),
+///     Vector2::new(2.0, 2.0),
+///     Vector2::new(0.0, 2.0),
+/// ];
+/// assert_eq!(signed_area(&square), 4.0);
+/// ```
+pub fn signed_area(vertices: &[Vector2]) -> f64 {
+    let n = vertices.len();
+    if n < 3 {
+        return 0.0;
+    }
+    let mut sum = 0.0;
+    for i in 0..n {
+        let p = &vertices[i];
+        let q = &vertices[(i + 1) % n];
+        sum += p.x * q.y - q.x * p.y;
+    }
+    sum / 2.0
+}
+
+/// Returns the absolute area of a polygon
+///
+/// # Examples
+///
+/// ```
+/// use svg_polygon_parser::polygon::area;
+/// use svg_polygon_parser::vector2::Vector2;
+///
+/// let tri = [
+///     Vector2::new(0.0, 0.0),
+///     Vector2::new(0.0, 2.0),
+///     Vector2::new(2.0, 0.0),
+/// ];
+/// assert_eq!(area(&tri), 2.0);
+/// ```
+pub fn area(vertices: &[Vector2]) -> f64 {
+    signed_area(vertices).abs()
+}
+
+/// Returns true when a polygon is wound clockwise
+///
+/// # Examples
+///
+/// ```
+/// use svg_polygon_parser::polygon::is_clockwise;
+/// use svg_polygon_parser::vector2::Vector2;
+///
+/// let cw = [
+///     Vector2::new(0.0, 0.0),
+///     Vector2::new(0.0, 2.0),
+///     Vector2::new(2.0, 2.0),
+///     Vector2::new(2.0, 0.0),
+/// ];
+/// assert!(is_clockwise(&cw));
+/// ```
+pub fn is_clockwise(vertices: &[Vector2]) -> bool {
+    signed_area(vertices) < 0.0
+}
+
+/// Returns the centroid of a polygon
+///
+/// Falls back to the average of the vertices when the polygon is
+/// degenerate (zero area).
+///
+/// # Examples
+///
+/// ```
+/// use svg_polygon_parser::polygon::centroid;
+/// use svg_polygon_parser::vector2::Vector2;
+///
+/// let square = [
+///     Vector2::new(0.0, 0.0),
+///     Vector2::new(2.0, 0.0),
+///     Vector2::new(2.0, 2.0),
+///     Vector2::new(0.0, 2.0),
+/// ];
+/// assert_eq!(centroid(&square), Vector2::new(1.0, 1.0));
+/// ```
+pub fn centroid(vertices: &[Vector2]) -> Vector2 {
+    let n = vertices.len();
+    if n == 0 {
+        return Vector2::origin();
+    }
+    let a = signed_area(vertices);
+    if a == 0.0 {
+        let mut acc = Vector2::origin();
+        for v in vertices {
+            acc = &acc + v;
+        }
+        return acc.divide(n as f64);
+    }
+    let mut cx = 0.0;
+    let mut cy = 0.0;
+    for i in 0..n {
+        let p = &vertices[i];
+        let q = &vertices[(i + 1) % n];
+        let w = p.x * q.y - q.x * p.y;
+        cx += (p.x + q.x) * w;
+        cy += (p.y + q.y) * w;
+    }
+    let f = 6.0 * a;
+    Vector2::new(cx / f, cy / f)
+}
@@ -0,0 +1,182 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+use crate::polygon::centroid;
+use crate::vector2::Vector2;
+
+/// A square candidate cell for the branch-and-bound search.
+struct Cell {
+    center: Vector2,
+    half: f64,
+    distance: f64,
+    potential: f64,
+}
+
+impl Cell {
+    fn new(center: Vector2, half: f64, vertices: &[Vector2]) -> Cell {
+        let distance = signed_distance(&center, vertices);
+        Cell {
+            center,
+            half,
+            distance,
+            potential: distance + half * std::f64::consts::SQRT_2,
+        }
+    }
+}
+
+impl PartialEq for Cell {
+    fn eq(&self, other: &Cell) -> bool {
+        self.potential == other.potential
+    }
+}
+
+impl Eq for Cell {}
+
+impl PartialOrd for Cell {
+    fn partial_cmp(&self, other: &Cell) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Cell {
+    fn cmp(&self, other: &Cell) -> Ordering {
+        self.potential
+            .partial_cmp(&other.potential)
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Finds the interior point farthest from any edge (pole of inaccessibility)
+///
+/// Returns the best point together with its distance to the boundary, using a
+/// max-heap branch-and-bound search refined until no cell can beat the current
+/// best by more than `precision`.
+///
+/// # Examples
+///
+/// ```
+/// use svg_polygon_parser::polylabel::pole_of_inaccessibility;
+/// use svg_polygon_parser::vector2::Vector2;
+///
+/// let square = [
+///     Vector2::new(0.0, 0.0),
+///     Vector2::new(4.0, 0.0),
+///     Vector2::new(4.0, 4.0),
+///     Vector2::new(0.0, 4.0),
+/// ];
+/// let (p, d) = pole_of_inaccessibility(&square, 0.01);
+/// assert!((p.x - 2.0).abs() < 0.05);
+/// assert!((p.y - 2.0).abs() < 0.05);
+/// assert!((d - 2.0).abs() < 0.05);
+/// ```
+pub fn pole_of_inaccessibility(vertices: &[Vector2], precision: f64) -> (Vector2, f64) {
+    if vertices.is_empty() {
+        return (Vector2::origin(), 0.0);
+    }
+
+    let mut min_x = vertices[0].x;
+    let mut min_y = vertices[0].y;
+    let mut max_x = vertices[0].x;
+    let mut max_y = vertices[0].y;
+    for v in vertices {
+        min_x = min_x.min(v.x);
+        min_y = min_y.min(v.y);
+        max_x = max_x.max(v.x);
+        max_y = max_y.max(v.y);
+    }
+
+    let width = max_x - min_x;
+    let height = max_y - min_y;
+    let cell_size = width.min(height);
+    if cell_size == 0.0 {
+        return (Vector2::new(min_x, min_y), 0.0);
+    }
+    let h = cell_size / 2.0;
+
+    let mut heap = BinaryHeap::new();
+    let mut x = min_x;
+    while x < max_x {
+        let mut y = min_y;
+        while y < max_y {
+            heap.push(Cell::new(
+                Vector2::new(x + h, y + h),
+                h,
+                vertices,
+            ));
+            y += cell_size;
+        }
+        x += cell_size;
+    }
+
+    // Seed the best candidate with the centroid.
+    let c = centroid(vertices);
+    let mut best = Cell::new(c, 0.0, vertices);
+
+    while let Some(cell) = heap.pop() {
+        if cell.distance > best.distance {
+            best = Cell::new(cell.center, 0.0, vertices);
+        }
+        if cell.potential - best.distance <= precision {
+            continue;
+        }
+        let half = cell.half / 2.0;
+        heap.push(Cell::new(
+            Vector2::new(cell.center.x - half, cell.center.y - half),
+            half,
+            vertices,
+        ));
+        heap.push(Cell::new(
+            Vector2::new(cell.center.x + half, cell.center.y - half),
+            half,
+            vertices,
+        ));
+        heap.push(Cell::new(
+            Vector2::new(cell.center.x - half, cell.center.y + half),
+            half,
+            vertices,
+        ));
+        heap.push(Cell::new(
+            Vector2::new(cell.center.x + half, cell.center.y + half),
+            half,
+            vertices,
+        ));
+    }
+
+    (best.center, best.distance)
+}
+
+/// Signed distance from a point to the polygon boundary (negative outside).
+fn signed_distance(p: &Vector2, vertices: &[Vector2]) -> f64 {
+    let n = vertices.len();
+    let mut inside = false;
+    let mut min_dist = f64::INFINITY;
+    let mut j = n - 1;
+    for i in 0..n {
+        let a = &vertices[i];
+        let b = &vertices[j];
+        if (a.y > p.y) != (b.y > p.y)
+            && p.x < (b.x - a.x) * (p.y - a.y) / (b.y - a.y) + a.x
+        {
+            inside = !inside;
+        }
+        min_dist = min_dist.min(segment_distance(p, a, b));
+        j = i;
+    }
+    if inside {
+        min_dist
+    } else {
+        -min_dist
+    }
+}
+
+/// Distance from a point to a line segment.
+fn segment_distance(p: &Vector2, a: &Vector2, b: &Vector2) -> f64 {
+    let ab = b - a;
+    let len2 = ab.dot(&ab);
+    if len2 == 0.0 {
+        return (p - a).norm();
+    }
+    let t = ((p - a).dot(&ab) / len2).clamp(0.0, 1.0);
+    let proj = a + &ab.multi(t);
+    (p - &proj).norm()
+}
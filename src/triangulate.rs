@@ -0,0 +1,89 @@
+use crate::polygon::signed_area;
+use crate::vector2::Vector2;
+
+/// Triangulates a simple polygon into a list of triangles
+///
+/// Uses ear clipping over a counter-clockwise copy of the input. After each
+/// clipped ear the scan restarts from the first remaining vertex and every ear
+/// test checks all remaining vertices, so the worst case is O(n³) rather than
+/// O(n²). Self-intersecting polygons are not supported; if a full pass over
+/// the remaining vertices finds no ear the routine bails out and returns the
+/// triangles collected so far.
+///
+/// # Examples
+///
+/// ```
+/// use svg_polygon_parser::triangulate::triangulate;
+/// use svg_polygon_parser::vector2::Vector2;
+///
+/// let square = [
+///     Vector2::new(0.0, 0.0),
+///     Vector2::new(2.0, 0.0),
+///     Vector2::new(2.0, 2.0),
+///     Vector2::new(0.0, 2.0),
+/// ];
+/// assert_eq!(triangulate(&square).len(), 2);
+/// ```
+pub fn triangulate(vertices: &[Vector2]) -> Vec<[Vector2; 3]> {
+    let mut points: Vec<Vector2> = vertices.to_vec();
+    if points.len() < 3 {
+        return Vec::new();
+    }
+    // Ensure counter-clockwise winding so convex ears have a positive cross.
+    if signed_area(&points) < 0.0 {
+        points.reverse();
+    }
+
+    let mut indices: Vec<usize> = (0..points.len()).collect();
+    let mut triangles = Vec::new();
+
+    while indices.len() > 2 {
+        let n = indices.len();
+        let mut clipped = false;
+        for i in 0..n {
+            let iu = indices[(i + n - 1) % n];
+            let iv = indices[i];
+            let iw = indices[(i + 1) % n];
+            if is_ear(&points, &indices, iu, iv, iw) {
+                triangles.push([points[iu], points[iv], points[iw]]);
+                indices.remove(i);
+                clipped = true;
+                break;
+            }
+        }
+        if !clipped {
+            break;
+        }
+    }
+
+    triangles
+}
+
+/// Returns true when `u, v, w` form a convex corner with no other vertex inside.
+fn is_ear(points: &[Vector2], indices: &[usize], iu: usize, iv: usize, iw: usize) -> bool {
+    let u = &points[iu];
+    let v = &points[iv];
+    let w = &points[iw];
+    if (v - u).cross(&(w - v)) <= 0.0 {
+        return false;
+    }
+    for &idx in indices {
+        if idx == iu || idx == iv || idx == iw {
+            continue;
+        }
+        if point_in_triangle(&points[idx], u, v, w) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Point-in-triangle test via three consistent cross-product signs.
+fn point_in_triangle(p: &Vector2, a: &Vector2, b: &Vector2, c: &Vector2) -> bool {
+    let d1 = (b - a).cross(&(p - a));
+    let d2 = (c - b).cross(&(p - b));
+    let d3 = (a - c).cross(&(p - c));
+    let has_neg = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+    let has_pos = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+    !(has_neg && has_pos)
+}
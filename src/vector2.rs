@@ -1,21 +1,22 @@
-use std::f64;
 use std::fmt;
 use std::ops;
 
+use num_traits::{Float, NumCast, Signed};
+
 #[derive(Debug, PartialEq, Copy, Clone)]
-pub struct Vector2 {
-    pub x: f64,
-    pub y: f64,
+pub struct Vector2<T = f64> {
+    pub x: T,
+    pub y: T,
 }
 
-impl fmt::Display for Vector2 {
+impl<T: fmt::Display> fmt::Display for Vector2<T> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "(x: {}, y: {})", self.x, self.y)
     }
 }
 
-impl ops::Add for &Vector2 {
-    type Output = Vector2;
+impl<T: ops::Add<Output = T> + Copy> ops::Add for &Vector2<T> {
+    type Output = Vector2<T>;
 
     /// Add tow vectors
     ///
@@ -28,7 +29,7 @@ impl ops::Add for &Vector2 {
     /// let v2 = Vector2::new(3.0, 4.0);
     /// assert_eq!(&v1 + &v2, Vector2::new(4.0, 6.0));
     /// ```
-    fn add(self, other: &Vector2) -> Vector2 {
+    fn add(self, other: &Vector2<T>) -> Vector2<T> {
         Vector2 {
             x: self.x + other.x,
             y: self.y + other.y,
@@ -36,8 +37,8 @@ impl ops::Add for &Vector2 {
     }
 }
 
-impl ops::Sub for &Vector2 {
-    type Output = Vector2;
+impl<T: ops::Sub<Output = T> + Copy> ops::Sub for &Vector2<T> {
+    type Output = Vector2<T>;
 
     /// Sub tow vectors
     ///
@@ -50,7 +51,7 @@ impl ops::Sub for &Vector2 {
     /// let v2 = Vector2::new(3.0, 4.0);
     /// assert_eq!(&v1 - &v2, Vector2::new(-2.0, -2.0));
     /// ```
-    fn sub(self, other: &Vector2) -> Vector2 {
+    fn sub(self, other: &Vector2<T>) -> Vector2<T> {
         Vector2 {
             x: self.x - other.x,
             y: self.y - other.y,
@@ -58,7 +59,7 @@ impl ops::Sub for &Vector2 {
     }
 }
 
-impl Vector2 {
+impl<T: Copy> Vector2<T> {
     /// Returns a Vector2
     ///
     /// # Examples
@@ -69,36 +70,48 @@ impl Vector2 {
     /// let v1 = Vector2::new(1.0, 2.0);
     /// assert_eq!(v1, Vector2 { x: 1.0, y: 2.0 });
     /// ```
-    pub fn new(x: f64, y: f64) -> Vector2 {
+    pub fn new(x: T, y: T) -> Vector2<T> {
         Vector2 { x, y }
     }
+}
 
-    /// Returns a origin
+impl<T: NumCast + Copy> Vector2<T> {
+    /// Returns the vector with its coordinates cast to another numeric type
+    ///
+    /// Returns `None` when a coordinate cannot be represented in `U`.
     ///
     /// # Examples
     ///
     /// ```
     /// use svg_polygon_parser::vector2::Vector2;
     ///
-    /// let v1 = Vector2::origin();
-    /// assert_eq!(v1, Vector2::new(0.0, 0.0));
+    /// let v = Vector2::new(1.0, 2.0);
+    /// assert_eq!(v.cast::<i32>(), Some(Vector2::new(1, 2)));
     /// ```
-    pub fn origin() -> Vector2 {
-        Vector2 { x: 0.0, y: 0.0 }
+    pub fn cast<U: NumCast>(&self) -> Option<Vector2<U>> {
+        Some(Vector2 {
+            x: U::from(self.x)?,
+            y: U::from(self.y)?,
+        })
     }
+}
 
-    /// Returns a norm
+impl<T: Signed + Copy> Vector2<T> {
+    /// Returns a origin
     ///
     /// # Examples
     ///
     /// ```
     /// use svg_polygon_parser::vector2::Vector2;
     ///
-    /// let v1 = Vector2::new(3.0, 4.0);
-    /// assert_eq!(v1.norm(), 5.0);
+    /// let v1 = Vector2::origin();
+    /// assert_eq!(v1, Vector2::new(0.0, 0.0));
     /// ```
-    pub fn norm(&self) -> f64 {
-        (self.x.powi(2) + self.y.powi(2)).sqrt()
+    pub fn origin() -> Vector2<T> {
+        Vector2 {
+            x: T::zero(),
+            y: T::zero(),
+        }
     }
 
     /// Returns true if a vector is zero
@@ -112,7 +125,7 @@ impl Vector2 {
     /// assert!(v.is_zero());
     /// ```
     pub fn is_zero(&self) -> bool {
-        self.norm() == 0.0
+        self.x.is_zero() && self.y.is_zero()
     }
 
     /// Returns a multiplied vector
@@ -125,7 +138,7 @@ impl Vector2 {
     /// let v = Vector2::new(3.0, 4.0);
     /// assert_eq!(v.multi(2.0), Vector2::new(6.0,  8.0));
     /// ```
-    pub fn multi(&self, c: f64) -> Vector2 {
+    pub fn multi(&self, c: T) -> Vector2<T> {
         Vector2 {
             x: self.x * c,
             y: self.y * c,
@@ -142,13 +155,93 @@ impl Vector2 {
     /// let v = Vector2::new(3.0, 4.0);
     /// assert_eq!(v.divide(2.0), Vector2::new(1.5,  2.0));
     /// ```
-    pub fn divide(&self, c: f64) -> Vector2 {
+    pub fn divide(&self, c: T) -> Vector2<T> {
         Vector2 {
             x: self.x / c,
             y: self.y / c,
         }
     }
 
+    /// Returns a dot product
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svg_polygon_parser::vector2::Vector2;
+    ///
+    /// let v1 = Vector2::new(1.0, 2.0);
+    /// let v2 = Vector2::new(3.0, 4.0);
+    /// assert_eq!(v1.dot(&v2), 11.0);
+    /// ```
+    pub fn dot(&self, other: &Vector2<T>) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Returns a scalar cross product (perp-dot)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svg_polygon_parser::vector2::Vector2;
+    ///
+    /// let v1 = Vector2::new(1.0, 0.0);
+    /// let v2 = Vector2::new(0.0, 1.0);
+    /// assert_eq!(v1.cross(&v2), 1.0);
+    /// ```
+    pub fn cross(&self, other: &Vector2<T>) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Returns self projected onto another vector
+    ///
+    /// # Examples
+    ///
+    /// A nonzero vector returns Ok
+    ///
+    /// ```
+    /// use svg_polygon_parser::vector2::Vector2;
+    ///
+    /// let v = Vector2::new(2.0, 3.0);
+    /// let onto = Vector2::new(1.0, 0.0);
+    /// assert_eq!(v.project_onto(&onto), Ok(Vector2::new(2.0, 0.0)));
+    /// ```
+    ///
+    /// # Failures
+    ///
+    /// A zero vector returns Err
+    ///
+    /// ```
+    /// use svg_polygon_parser::vector2::Vector2;
+    ///
+    /// let v = Vector2::new(2.0, 3.0);
+    /// let onto = Vector2::new(0.0, 0.0);
+    /// assert_eq!(v.project_onto(&onto), Err(Vector2::new(0.0, 0.0)));
+    /// ```
+    pub fn project_onto(&self, other: &Vector2<T>) -> Result<Vector2<T>, Vector2<T>> {
+        let d = other.dot(other);
+        if d.is_zero() {
+            Err(Vector2::origin())
+        } else {
+            Ok(other.multi(self.dot(other) / d))
+        }
+    }
+}
+
+impl<T: Float + Signed> Vector2<T> {
+    /// Returns a norm
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svg_polygon_parser::vector2::Vector2;
+    ///
+    /// let v1 = Vector2::new(3.0, 4.0);
+    /// assert_eq!(v1.norm(), 5.0);
+    /// ```
+    pub fn norm(&self) -> T {
+        (self.x.powi(2) + self.y.powi(2)).sqrt()
+    }
+
     /// Returns a unit vector
     ///
     /// # Examples
@@ -172,9 +265,9 @@ impl Vector2 {
     /// let v = Vector2::new(0.0, 0.0);
     /// assert_eq!(v.unit(), Err(Vector2::new(0.0, 0.0)));
     /// ```
-    pub fn unit(&self) -> Result<Vector2, Vector2> {
+    pub fn unit(&self) -> Result<Vector2<T>, Vector2<T>> {
         let n = self.norm();
-        if n == 0.0 {
+        if n.is_zero() {
             Err(Vector2::origin())
         } else {
             Ok(self.divide(n))
@@ -194,7 +287,58 @@ impl Vector2 {
     /// let v2 = Vector2::new(1.0, -1.0);
     /// assert_eq!(v2.radian(), -std::f64::consts::FRAC_PI_4);
     /// ```
-    pub fn radian(&self) -> f64 {
+    pub fn radian(&self) -> T {
         self.y.atan2(self.x)
     }
+
+    /// Returns a signed angle to another vector
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svg_polygon_parser::vector2::Vector2;
+    ///
+    /// let v1 = Vector2::new(1.0, 0.0);
+    /// let v2 = Vector2::new(0.0, 1.0);
+    /// assert_eq!(v1.angle_to(&v2), std::f64::consts::FRAC_PI_2);
+    /// ```
+    pub fn angle_to(&self, other: &Vector2<T>) -> T {
+        self.cross(other).atan2(self.dot(other))
+    }
+
+    /// Returns a rotated vector
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svg_polygon_parser::vector2::Vector2;
+    ///
+    /// let v = Vector2::new(1.0, 0.0);
+    /// let r = v.rotate(std::f64::consts::FRAC_PI_2);
+    /// assert!((r.x - 0.0).abs() < 1e-10);
+    /// assert!((r.y - 1.0).abs() < 1e-10);
+    /// ```
+    pub fn rotate(&self, theta: T) -> Vector2<T> {
+        let (s, c) = theta.sin_cos();
+        Vector2 {
+            x: self.x * c - self.y * s,
+            y: self.x * s + self.y * c,
+        }
+    }
+
+    /// Returns self reflected about a unit normal
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use svg_polygon_parser::vector2::Vector2;
+    ///
+    /// let v = Vector2::new(1.0, -1.0);
+    /// let n = Vector2::new(0.0, 1.0);
+    /// assert_eq!(v.reflect(&n), Vector2::new(1.0, 1.0));
+    /// ```
+    pub fn reflect(&self, normal: &Vector2<T>) -> Vector2<T> {
+        let two = T::from(2.0).unwrap();
+        self - &normal.multi(two * self.dot(normal))
+    }
 }